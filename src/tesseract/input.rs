@@ -7,6 +7,116 @@ use std::{
 
 use crate::{TessError, TessResult};
 
+/// Page segmentation mode, passed to Tesseract via `--psm`.
+///
+/// See `tesseract --help-psm` for the upstream documentation of each mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageSegMode {
+    /// Orientation and script detection (OSD) only.
+    OsdOnly = 0,
+    /// Automatic page segmentation with OSD.
+    AutoOsd = 1,
+    /// Automatic page segmentation, but no OSD, or OCR.
+    AutoOnly = 2,
+    /// Fully automatic page segmentation, but no OSD. (default)
+    Auto = 3,
+    /// Assume a single column of text of variable sizes.
+    SingleColumn = 4,
+    /// Assume a single uniform block of vertically aligned text.
+    SingleBlockVertText = 5,
+    /// Assume a single uniform block of text.
+    SingleBlock = 6,
+    /// Treat the image as a single text line.
+    SingleLine = 7,
+    /// Treat the image as a single word.
+    SingleWord = 8,
+    /// Treat the image as a single word in a circle.
+    CircleWord = 9,
+    /// Treat the image as a single character.
+    SingleChar = 10,
+    /// Sparse text. Find as much text as possible in no particular order.
+    SparseText = 11,
+    /// Sparse text with OSD.
+    SparseTextOsd = 12,
+    /// Treat the image as a single text line, bypassing hacks that are Tesseract-specific.
+    RawLine = 13,
+}
+
+impl PageSegMode {
+    pub fn to_i32(self) -> i32 {
+        self as i32
+    }
+
+    pub(crate) fn as_arg(self) -> String {
+        self.to_i32().to_string()
+    }
+}
+
+impl TryFrom<i32> for PageSegMode {
+    type Error = TessError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::OsdOnly),
+            1 => Ok(Self::AutoOsd),
+            2 => Ok(Self::AutoOnly),
+            3 => Ok(Self::Auto),
+            4 => Ok(Self::SingleColumn),
+            5 => Ok(Self::SingleBlockVertText),
+            6 => Ok(Self::SingleBlock),
+            7 => Ok(Self::SingleLine),
+            8 => Ok(Self::SingleWord),
+            9 => Ok(Self::CircleWord),
+            10 => Ok(Self::SingleChar),
+            11 => Ok(Self::SparseText),
+            12 => Ok(Self::SparseTextOsd),
+            13 => Ok(Self::RawLine),
+            _ => Err(TessError::ArgsError(format!(
+                "{value} is not a valid page segmentation mode (expected 0-13)"
+            ))),
+        }
+    }
+}
+
+/// OCR engine mode, passed to Tesseract via `--oem`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OcrEngineMode {
+    /// Legacy engine only.
+    TesseractOnly = 0,
+    /// Neural nets LSTM engine only.
+    LstmOnly = 1,
+    /// Legacy + LSTM engines.
+    TesseractLstmCombined = 2,
+    /// Default, based on what is available.
+    Default = 3,
+}
+
+impl OcrEngineMode {
+    pub fn to_i32(self) -> i32 {
+        self as i32
+    }
+
+    pub(crate) fn as_arg(self) -> String {
+        self.to_i32().to_string()
+    }
+}
+
+impl TryFrom<i32> for OcrEngineMode {
+    type Error = TessError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::TesseractOnly),
+            1 => Ok(Self::LstmOnly),
+            2 => Ok(Self::TesseractLstmCombined),
+            3 => Ok(Self::Default),
+            _ => Err(TessError::ArgsError(format!(
+                "{value} is not a valid OCR engine mode (expected 0-3)"
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Args {
     pub executable: Option<String>,
@@ -14,8 +124,8 @@ pub struct Args {
     pub lang: String,
     pub config_variables: HashMap<String, String>,
     pub dpi: Option<i32>,
-    pub psm: Option<i32>,
-    pub oem: Option<i32>,
+    pub psm: Option<PageSegMode>,
+    pub oem: Option<OcrEngineMode>,
 }
 
 impl Default for Args {
@@ -26,8 +136,8 @@ impl Default for Args {
             lang: "eng".into(),
             config_variables: HashMap::new(),
             dpi: Some(150),
-            psm: Some(3),
-            oem: Some(3),
+            psm: Some(PageSegMode::Auto),
+            oem: Some(OcrEngineMode::Default),
         }
     }
 }
@@ -39,6 +149,35 @@ impl Args {
             .map(|(key, value)| format!("{}={}", key, value))
             .collect::<Vec<_>>()
     }
+
+    /// Sets `psm`/`oem` from raw, untyped integers, validating both against the ranges
+    /// Tesseract documents and returning a `TessError` if either is out of range. Composes with
+    /// `Default::default()` (or any other `Args` value) for callers migrating off the old
+    /// `Option<i32>` fields: `Args::default().with_raw_psm_oem(Some(6), None)?`.
+    pub fn with_raw_psm_oem(mut self, psm: Option<i32>, oem: Option<i32>) -> TessResult<Self> {
+        self.psm = psm.map(PageSegMode::try_from).transpose()?;
+        self.oem = oem.map(OcrEngineMode::try_from).transpose()?;
+        Ok(self)
+    }
+}
+
+/// Encoding used when writing a `DynamicImage` out to a tempfile for OCR.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Lossless; the default used by the zero-config `from_dynamic_image`.
+    Png,
+    /// Lossy, usually faster to encode and smaller on disk for large rasters. Quality must be
+    /// in `1..=100`.
+    Jpeg(u8),
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Png => "png",
+            Format::Jpeg(_) => "jpg",
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -49,10 +188,48 @@ pub struct Image {
 impl Image {
     pub fn from_path<P: Into<PathBuf>>(path: P) -> TessResult<Self> {
         let path = path.into();
-        Self::check_image_format(&path)?;
-        Ok(Self {
-            data: InputData::Path(path),
-        })
+        if Self::check_image_format(&path).is_ok() {
+            return Ok(Self {
+                data: InputData::Path(path),
+            });
+        }
+
+        // The extension isn't one Tesseract's CLI reads directly: decode the file based on
+        // its actual content and transcode it to a PNG tempfile instead of giving up.
+        let image = image::io::Reader::open(&path)
+            .map_err(|_| TessError::ImageNotFoundError)?
+            .with_guessed_format()
+            .map_err(|_| TessError::ImageFormatError)?
+            .decode()
+            .map_err(|e| TessError::DynamicImageError(e.to_string()))?;
+
+        Self::from_dynamic_image(&image)
+    }
+
+    /// Builds an `Image` from an in-memory byte buffer, detecting the real format from its
+    /// content (not a filename) via `image::guess_format`. Formats Tesseract's CLI can read are
+    /// written out as-is; anything else is decoded and transcoded to a PNG tempfile. Formats
+    /// without a magic number (such as TGA) cannot be detected and yield `ImageFormatError`.
+    pub fn from_bytes(bytes: &[u8]) -> TessResult<Self> {
+        let format = image::guess_format(bytes).map_err(|_| TessError::ImageFormatError)?;
+
+        if let Some(extension) = Self::tesseract_extension(format, bytes) {
+            let tempfile = tempfile::Builder::new()
+                .prefix("rusty-tesseract")
+                .suffix(&format!(".{extension}"))
+                .tempfile()
+                .map_err(|e| TessError::TempfileError(e.to_string()))?;
+            std::fs::write(tempfile.path(), bytes)
+                .map_err(|e| TessError::TempfileError(e.to_string()))?;
+
+            return Ok(Self {
+                data: InputData::Image(tempfile),
+            });
+        }
+
+        let image =
+            image::load_from_memory(bytes).map_err(|e| TessError::DynamicImageError(e.to_string()))?;
+        Self::from_dynamic_image(&image)
     }
 
     fn check_image_format(path: &Path) -> TessResult<()> {
@@ -72,23 +249,86 @@ impl Image {
         }
     }
 
+    /// Maps a content-detected `image::ImageFormat` to the extension to use when Tesseract's
+    /// CLI can read that format directly, mirroring the list in `check_image_format`. `Pnm` is
+    /// one `image::ImageFormat` covering three distinct on-disk extensions, so `bytes` is
+    /// inspected for the PNM magic number to tell them apart.
+    fn tesseract_extension(format: image::ImageFormat, bytes: &[u8]) -> Option<&'static str> {
+        use image::ImageFormat;
+        match format {
+            ImageFormat::Jpeg => Some("jpg"),
+            ImageFormat::Png => Some("png"),
+            ImageFormat::Pnm => Some(Self::pnm_extension(bytes)),
+            ImageFormat::Tiff => Some("tiff"),
+            ImageFormat::Bmp => Some("bmp"),
+            ImageFormat::Gif => Some("gif"),
+            ImageFormat::WebP => Some("webp"),
+            _ => None,
+        }
+    }
+
+    /// Disambiguates the PNM magic number (`P1`/`P4` bitmap, `P2`/`P5` greymap, `P3`/`P6`
+    /// pixmap) into the extension Tesseract expects for that subformat.
+    fn pnm_extension(bytes: &[u8]) -> &'static str {
+        match bytes.get(1) {
+            Some(b'1') | Some(b'4') => "pbm",
+            Some(b'2') | Some(b'5') => "pgm",
+            Some(b'3') | Some(b'6') => "ppm",
+            _ => "pbm",
+        }
+    }
+
     pub fn from_dynamic_image(image: &DynamicImage) -> TessResult<Self> {
+        Self::from_dynamic_image_with_format(image, Format::Png)
+    }
+
+    /// Like [`Image::from_dynamic_image`], but encodes the tempfile as `format` instead of
+    /// always using PNG. Large rasters sometimes OCR faster and use less temp disk as JPEG;
+    /// PNG stays the lossless default for the zero-config call.
+    pub fn from_dynamic_image_with_format(image: &DynamicImage, format: Format) -> TessResult<Self> {
+        if let Format::Jpeg(quality) = format {
+            if !(1..=100).contains(&quality) {
+                return Err(TessError::DynamicImageError(format!(
+                    "JPEG quality must be between 1 and 100, got {quality}"
+                )));
+            }
+        }
+
         //Store Image as Tempfile
         let tempfile = tempfile::Builder::new()
             .prefix("rusty-tesseract")
-            .suffix(".png")
+            .suffix(&format!(".{}", format.extension()))
             .tempfile()
             .map_err(|e| TessError::TempfileError(e.to_string()))?;
         let path = tempfile.path();
-        image
-            .save_with_format(path, image::ImageFormat::Png)
-            .map_err(|e| TessError::DynamicImageError(e.to_string()))?;
+
+        match format {
+            Format::Png => image
+                .save_with_format(path, image::ImageFormat::Png)
+                .map_err(|e| TessError::DynamicImageError(e.to_string()))?,
+            Format::Jpeg(quality) => {
+                let file = std::fs::File::create(path)
+                    .map_err(|e| TessError::TempfileError(e.to_string()))?;
+                image::codecs::jpeg::JpegEncoder::new_with_quality(file, quality)
+                    .encode_image(image)
+                    .map_err(|e| TessError::DynamicImageError(e.to_string()))?;
+            }
+        }
 
         Ok(Self {
             data: InputData::Image(tempfile),
         })
     }
 
+    /// Like [`Image::from_dynamic_image`], but runs `preprocessor` over `image` first.
+    pub fn from_dynamic_image_with(
+        image: &DynamicImage,
+        preprocessor: Preprocessor,
+    ) -> TessResult<Self> {
+        let processed = preprocessor.apply(image.clone());
+        Self::from_dynamic_image(&processed)
+    }
+
     pub fn get_image_path(&self) -> TessResult<&str> {
         match &self.data {
             InputData::Path(x) => x.to_str(),
@@ -104,6 +344,139 @@ enum InputData {
     Image(tempfile::NamedTempFile),
 }
 
+#[derive(Clone, Copy, Debug)]
+enum PreprocessStep {
+    Grayscale,
+    ThresholdOtsu,
+    ScaleToDpi(u32),
+}
+
+/// A chainable image-preprocessing pipeline run on a `DynamicImage` before it becomes an
+/// `Image`, e.g. `Preprocessor::new().grayscale().threshold_otsu().scale_to_dpi(300)` passed to
+/// [`Image::from_dynamic_image_with`].
+#[derive(Clone, Debug, Default)]
+pub struct Preprocessor {
+    steps: Vec<PreprocessStep>,
+}
+
+impl Preprocessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts the image to grayscale.
+    pub fn grayscale(mut self) -> Self {
+        self.steps.push(PreprocessStep::Grayscale);
+        self
+    }
+
+    /// Binarizes the image using Otsu's method: pixels at or below the computed threshold
+    /// become black, the rest become white.
+    pub fn threshold_otsu(mut self) -> Self {
+        self.steps.push(PreprocessStep::ThresholdOtsu);
+        self
+    }
+
+    /// Resizes the image so that it approximates `dpi`, assuming an input resolution of
+    /// [`Preprocessor::ASSUMED_SOURCE_DPI`] (typical for screenshots and other non-scanned
+    /// sources); a `dpi` below that shrinks the image. Tesseract's accuracy degrades below
+    /// ~300 DPI.
+    pub fn scale_to_dpi(mut self, dpi: u32) -> Self {
+        self.steps.push(PreprocessStep::ScaleToDpi(dpi));
+        self
+    }
+
+    const ASSUMED_SOURCE_DPI: u32 = 96;
+
+    pub(crate) fn apply(&self, image: DynamicImage) -> DynamicImage {
+        let mut image = image;
+        for step in &self.steps {
+            image = match step {
+                PreprocessStep::Grayscale => image.grayscale(),
+                PreprocessStep::ThresholdOtsu => Self::apply_threshold_otsu(image),
+                PreprocessStep::ScaleToDpi(dpi) => Self::apply_scale_to_dpi(image, *dpi),
+            };
+        }
+        image
+    }
+
+    fn apply_scale_to_dpi(image: DynamicImage, target_dpi: u32) -> DynamicImage {
+        let scale = f64::from(target_dpi) / f64::from(Self::ASSUMED_SOURCE_DPI);
+        let new_width = ((f64::from(image.width()) * scale).round() as u32).max(1);
+        let new_height = ((f64::from(image.height()) * scale).round() as u32).max(1);
+        image.resize_exact(new_width, new_height, image::imageops::FilterType::Lanczos3)
+    }
+
+    fn apply_threshold_otsu(image: DynamicImage) -> DynamicImage {
+        let gray = image.to_luma8();
+        let threshold = Self::otsu_threshold(&gray);
+
+        let binarized = image::ImageBuffer::from_fn(gray.width(), gray.height(), |x, y| {
+            if gray.get_pixel(x, y)[0] <= threshold {
+                image::Luma([0u8])
+            } else {
+                image::Luma([255u8])
+            }
+        });
+
+        DynamicImage::ImageLuma8(binarized)
+    }
+
+    /// Otsu's method: builds a 256-bin histogram of `gray`, normalizes it to probabilities, then
+    /// scans candidate thresholds maintaining the cumulative background weight and mean so the
+    /// between-class variance at each threshold is an O(1) update rather than a full rescan.
+    /// Returns the threshold that maximizes that variance.
+    fn otsu_threshold(gray: &image::GrayImage) -> u8 {
+        let mut histogram = [0u32; 256];
+        for pixel in gray.pixels() {
+            histogram[pixel[0] as usize] += 1;
+        }
+
+        let total_pixels = (gray.width() as u64 * gray.height() as u64) as f64;
+        if total_pixels == 0.0 {
+            return 128;
+        }
+
+        let probabilities: Vec<f64> = histogram
+            .iter()
+            .map(|&count| count as f64 / total_pixels)
+            .collect();
+        let global_mean: f64 = probabilities
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| i as f64 * p)
+            .sum();
+
+        let mut w0 = 0.0; // cumulative background class weight
+        let mut sum0 = 0.0; // cumulative background intensity sum
+        let mut best_threshold = 0u8;
+        let mut best_variance = 0.0;
+
+        for (t, &p) in probabilities.iter().enumerate() {
+            w0 += p;
+            if w0 == 0.0 {
+                continue;
+            }
+            let w1 = 1.0 - w0;
+            if w1 <= 0.0 {
+                break;
+            }
+
+            sum0 += t as f64 * p;
+            let mu0 = sum0 / w0;
+            let mu1 = (global_mean - sum0) / w1;
+
+            let between_class_variance = w0 * w1 * (mu0 - mu1).powi(2);
+            if between_class_variance > best_variance {
+                best_variance = between_class_variance;
+                best_threshold = t as u8;
+            }
+        }
+
+        best_threshold
+    }
+}
+
 impl fmt::Display for Image {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.get_image_path().unwrap())
@@ -112,8 +485,47 @@ impl fmt::Display for Image {
 
 #[cfg(test)]
 mod tests {
-    use super::Image;
+    use super::{Args, Format, Image, OcrEngineMode, PageSegMode, Preprocessor};
     use image::io::Reader as ImageReader;
+    use image::DynamicImage;
+
+    #[test]
+    fn test_page_seg_mode_try_from_valid_bounds() {
+        assert_eq!(PageSegMode::try_from(0).unwrap(), PageSegMode::OsdOnly);
+        assert_eq!(PageSegMode::try_from(13).unwrap(), PageSegMode::RawLine);
+    }
+
+    #[test]
+    fn test_page_seg_mode_try_from_invalid() {
+        assert!(PageSegMode::try_from(-1).is_err());
+        assert!(PageSegMode::try_from(14).is_err());
+    }
+
+    #[test]
+    fn test_ocr_engine_mode_try_from_valid_bounds() {
+        assert_eq!(OcrEngineMode::try_from(0).unwrap(), OcrEngineMode::TesseractOnly);
+        assert_eq!(OcrEngineMode::try_from(3).unwrap(), OcrEngineMode::Default);
+    }
+
+    #[test]
+    fn test_ocr_engine_mode_try_from_invalid() {
+        assert!(OcrEngineMode::try_from(-1).is_err());
+        assert!(OcrEngineMode::try_from(4).is_err());
+    }
+
+    #[test]
+    fn test_args_with_raw_psm_oem_valid() {
+        let args = Args::default().with_raw_psm_oem(Some(6), Some(1)).unwrap();
+
+        assert_eq!(args.psm, Some(PageSegMode::SingleBlock));
+        assert_eq!(args.oem, Some(OcrEngineMode::LstmOnly));
+    }
+
+    #[test]
+    fn test_args_with_raw_psm_oem_invalid() {
+        assert!(Args::default().with_raw_psm_oem(Some(14), None).is_err());
+        assert!(Args::default().with_raw_psm_oem(None, Some(4)).is_err());
+    }
 
     #[test]
     fn test_from_path() {
@@ -137,4 +549,92 @@ mod tests {
 
         assert_eq!(img, tempimg);
     }
+
+    #[test]
+    fn test_from_bytes_supported_format() {
+        let img = ImageReader::open("img/string.png").unwrap().decode().unwrap();
+        let mut bytes = Vec::new();
+        img.write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+
+        let input = Image::from_bytes(&bytes).unwrap();
+        let temppath = input.get_image_path().unwrap();
+        assert!(temppath.ends_with(".png"));
+
+        let roundtripped = ImageReader::open(temppath).unwrap().decode().unwrap();
+        assert_eq!(img, roundtripped);
+    }
+
+    #[test]
+    fn test_from_bytes_transcodes_unsupported_format() {
+        // Farbfeld stores RGBA16 only, so compare against an RGBA16 copy of the source.
+        let img = DynamicImage::ImageRgba16(
+            ImageReader::open("img/string.png").unwrap().decode().unwrap().to_rgba16(),
+        );
+        let mut bytes = Vec::new();
+        img.write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Farbfeld)
+            .unwrap();
+
+        let input = Image::from_bytes(&bytes).unwrap();
+        let temppath = input.get_image_path().unwrap();
+        assert!(temppath.ends_with(".png"));
+
+        let roundtripped = ImageReader::open(temppath).unwrap().decode().unwrap();
+        assert_eq!(img, roundtripped);
+    }
+
+    #[test]
+    fn test_from_dynamic_image_with_format_jpeg() {
+        let img = ImageReader::open("img/string.png").unwrap().decode().unwrap();
+
+        let input = Image::from_dynamic_image_with_format(&img, Format::Jpeg(80)).unwrap();
+        let temppath = input.get_image_path().unwrap();
+
+        assert!(temppath.ends_with(".jpg"));
+        ImageReader::open(temppath).unwrap().decode().unwrap();
+    }
+
+    #[test]
+    fn test_from_dynamic_image_with_format_rejects_invalid_quality() {
+        let img = ImageReader::open("img/string.png").unwrap().decode().unwrap();
+
+        assert!(Image::from_dynamic_image_with_format(&img, Format::Jpeg(0)).is_err());
+        assert!(Image::from_dynamic_image_with_format(&img, Format::Jpeg(101)).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_disambiguates_pnm_subformat() {
+        let mut bytes = b"P5\n2 2\n255\n".to_vec();
+        bytes.extend_from_slice(&[128, 128, 128, 128]);
+
+        let input = Image::from_bytes(&bytes).unwrap();
+        let temppath = input.get_image_path().unwrap();
+
+        assert!(temppath.ends_with(".pgm"));
+    }
+
+    #[test]
+    fn test_threshold_otsu_binarizes_bimodal_image() {
+        let mut img = image::RgbImage::new(10, 10);
+        for (x, _y, pixel) in img.enumerate_pixels_mut() {
+            *pixel = if x < 5 {
+                image::Rgb([0, 0, 0])
+            } else {
+                image::Rgb([255, 255, 255])
+            };
+        }
+        let dynamic = DynamicImage::ImageRgb8(img);
+
+        let input = Image::from_dynamic_image_with(
+            &dynamic,
+            Preprocessor::new().grayscale().threshold_otsu(),
+        )
+        .unwrap();
+
+        let temppath = input.get_image_path().unwrap();
+        let binarized = ImageReader::open(temppath).unwrap().decode().unwrap().to_luma8();
+
+        assert_eq!(binarized.get_pixel(0, 0)[0], 0);
+        assert_eq!(binarized.get_pixel(9, 9)[0], 255);
+    }
 }